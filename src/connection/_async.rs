@@ -0,0 +1,205 @@
+/**
+ * [Asynchronous Command Processing](https://www.postgresql.org/docs/current/libpq-async.html)
+ */
+impl Connection {
+    /**
+     * Submits a command to the server without waiting for the result(s).
+     *
+     * See [PQsendQuery](https://www.postgresql.org/docs/current/libpq-async.html#LIBPQ-PQSENDQUERY).
+     */
+    pub fn send_query(&self, command: &str) -> crate::errors::Result {
+        let success = unsafe { pq_sys::PQsendQuery(self.into(), crate::cstr!(command)) };
+
+        if success == 1 {
+            Ok(())
+        } else {
+            Err(crate::errors::Error::Unknow)
+        }
+    }
+
+    /**
+     * Submits a command with parameters to the server without waiting for
+     * the result(s).
+     *
+     * See [PQsendQueryParams](https://www.postgresql.org/docs/current/libpq-async.html#LIBPQ-PQSENDQUERYPARAMS).
+     */
+    pub fn send_query_params(&self, command: &str, param_values: &[Option<&str>]) -> crate::errors::Result {
+        let owned: Vec<_> =
+            param_values.iter().map(|value| value.map(|value| std::ffi::CString::new(value).unwrap())).collect();
+        let param_values: Vec<_> =
+            owned.iter().map(|value| value.as_ref().map_or(std::ptr::null(), |value| value.as_ptr())).collect();
+
+        let success = unsafe {
+            pq_sys::PQsendQueryParams(
+                self.into(),
+                crate::cstr!(command),
+                param_values.len() as i32,
+                std::ptr::null(),
+                param_values.as_ptr(),
+                std::ptr::null(),
+                std::ptr::null(),
+                0,
+            )
+        };
+
+        if success == 1 {
+            Ok(())
+        } else {
+            Err(crate::errors::Error::Unknow)
+        }
+    }
+
+    /**
+     * Sends a request to create a prepared statement with the given
+     * parameters, without waiting for completion.
+     *
+     * See [PQsendPrepare](https://www.postgresql.org/docs/current/libpq-async.html#LIBPQ-PQSENDPREPARE).
+     */
+    pub fn send_prepare(&self, name: &str, command: &str) -> crate::errors::Result {
+        let success = unsafe {
+            pq_sys::PQsendPrepare(self.into(), crate::cstr!(name), crate::cstr!(command), 0, std::ptr::null())
+        };
+
+        if success == 1 {
+            Ok(())
+        } else {
+            Err(crate::errors::Error::Unknow)
+        }
+    }
+
+    /**
+     * Sends a request to execute a prepared statement with given
+     * parameters, without waiting for the result(s).
+     *
+     * See [PQsendQueryPrepared](https://www.postgresql.org/docs/current/libpq-async.html#LIBPQ-PQSENDQUERYPREPARED).
+     */
+    pub fn send_query_prepared(&self, name: &str, param_values: &[Option<&str>]) -> crate::errors::Result {
+        let owned: Vec<_> =
+            param_values.iter().map(|value| value.map(|value| std::ffi::CString::new(value).unwrap())).collect();
+        let param_values: Vec<_> =
+            owned.iter().map(|value| value.as_ref().map_or(std::ptr::null(), |value| value.as_ptr())).collect();
+
+        let success = unsafe {
+            pq_sys::PQsendQueryPrepared(
+                self.into(),
+                crate::cstr!(name),
+                param_values.len() as i32,
+                param_values.as_ptr(),
+                std::ptr::null(),
+                std::ptr::null(),
+                0,
+            )
+        };
+
+        if success == 1 {
+            Ok(())
+        } else {
+            Err(crate::errors::Error::Unknow)
+        }
+    }
+
+    /**
+     * Consumes any available input from the server without blocking.
+     *
+     * See [PQconsumeInput](https://www.postgresql.org/docs/current/libpq-async.html#LIBPQ-PQCONSUMEINPUT).
+     */
+    pub fn consume_input(&self) -> crate::errors::Result {
+        let success = unsafe { pq_sys::PQconsumeInput(self.into()) };
+
+        if success == 1 {
+            Ok(())
+        } else {
+            Err(crate::errors::Error::Unknow)
+        }
+    }
+
+    /**
+     * Returns `true` if a command is busy, meaning [`get_result`](Connection::get_result)
+     * would block waiting for input.
+     *
+     * See [PQisBusy](https://www.postgresql.org/docs/current/libpq-async.html#LIBPQ-PQISBUSY).
+     */
+    pub fn is_busy(&self) -> bool {
+        unsafe { pq_sys::PQisBusy(self.into()) == 1 }
+    }
+
+    /**
+     * Attempts to flush any queued output data to the server.
+     *
+     * Returns `Ok(true)` if some data is still queued, `Ok(false)` if
+     * everything has been sent.
+     *
+     * See [PQflush](https://www.postgresql.org/docs/current/libpq-async.html#LIBPQ-PQFLUSH).
+     */
+    pub fn flush(&self) -> crate::errors::Result<bool> {
+        match unsafe { pq_sys::PQflush(self.into()) } {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(crate::errors::Error::Unknow),
+        }
+    }
+
+    /**
+     * Retrieves the next result from a previously submitted asynchronous
+     * command, or `None` once the command is fully drained.
+     *
+     * See [PQgetResult](https://www.postgresql.org/docs/current/libpq-async.html#LIBPQ-PQGETRESULT).
+     */
+    pub fn get_result(&self) -> Option<crate::Result> {
+        let raw = unsafe { pq_sys::PQgetResult(self.into()) };
+
+        if raw.is_null() {
+            None
+        } else {
+            Some(raw.into())
+        }
+    }
+
+    /**
+     * Returns the file descriptor of the connection's socket, for use in
+     * an event loop that waits for the connection to become readable.
+     *
+     * Returns `None` if the connection is not currently open (`PQsocket`
+     * returned `-1`).
+     *
+     * See [PQsocket](https://www.postgresql.org/docs/current/libpq-async.html#LIBPQ-PQSOCKET).
+     */
+    pub fn socket(&self) -> Option<std::os::unix::io::RawFd> {
+        let fd = unsafe { pq_sys::PQsocket(self.into()) };
+
+        if fd == -1 {
+            None
+        } else {
+            Some(fd)
+        }
+    }
+
+    /**
+     * Returns the next pending notification from a `LISTEN`ed channel, or
+     * `None` if none is available.
+     *
+     * Call [`consume_input`](Connection::consume_input) first so libpq has
+     * a chance to read any notifications waiting on the socket.
+     *
+     * See [PQnotifies](https://www.postgresql.org/docs/current/libpq-notify.html#LIBPQ-PQNOTIFIES).
+     */
+    pub fn notifies(&self) -> Option<crate::Notify> {
+        let raw = unsafe { pq_sys::PQnotifies(self.into()) };
+
+        if raw.is_null() {
+            None
+        } else {
+            let notify = unsafe {
+                crate::Notify {
+                    channel: crate::ffi::to_string((*raw).relname),
+                    backend_pid: (*raw).be_pid,
+                    payload: crate::ffi::to_string((*raw).extra),
+                }
+            };
+
+            unsafe { pq_sys::PQfreemem(raw as *mut std::ffi::c_void) };
+
+            Some(notify)
+        }
+    }
+}
@@ -0,0 +1,194 @@
+/**
+ * The outcome of [`Connection::get_copy_data`].
+ */
+pub enum CopyResult {
+    /**
+     * A row of COPY data.
+     */
+    Row(Vec<u8>),
+
+    /**
+     * No row is available yet; only returned when called with `async_ = true`.
+     */
+    WouldBlock,
+
+    /**
+     * The COPY has finished; no more rows will be produced.
+     */
+    Done,
+
+    /**
+     * An error occurred while receiving COPY data; no more rows will be
+     * produced. The string is the connection's current error message.
+     */
+    Error(String),
+}
+
+/**
+ * [Functions Associated with the COPY Command](https://www.postgresql.org/docs/current/libpq-copy.html)
+ */
+impl Connection {
+    /**
+     * Sends data to the server during `COPY_IN` state.
+     *
+     * See [PQputCopyData](https://www.postgresql.org/docs/current/libpq-copy.html#LIBPQ-PQPUTCOPYDATA).
+     */
+    pub fn put_copy_data(&self, buf: &[u8]) -> crate::errors::Result {
+        let success = unsafe {
+            pq_sys::PQputCopyData(self.into(), buf.as_ptr() as *const std::ffi::c_void, buf.len() as i32)
+        };
+
+        if success == 1 {
+            Ok(())
+        } else {
+            Err(crate::errors::Error::Unknow)
+        }
+    }
+
+    /**
+     * Sends end-of-data indication to the server during `COPY_IN` state.
+     *
+     * `error` can be used to force the COPY to fail, with the given string
+     * used as the error message.
+     *
+     * See [PQputCopyEnd](https://www.postgresql.org/docs/current/libpq-copy.html#LIBPQ-PQPUTCOPYEND).
+     */
+    pub fn put_copy_end(&self, error: Option<&str>) -> crate::errors::Result {
+        let success = match error {
+            Some(error) => unsafe { pq_sys::PQputCopyEnd(self.into(), crate::cstr!(error)) },
+            None => unsafe { pq_sys::PQputCopyEnd(self.into(), std::ptr::null()) },
+        };
+
+        if success == 1 {
+            Ok(())
+        } else {
+            Err(crate::errors::Error::Unknow)
+        }
+    }
+
+    /**
+     * Receives data from the server during `COPY_OUT` state.
+     *
+     * When `async_` is `true`, returns [`CopyResult::WouldBlock`] instead of
+     * blocking until a row is available.
+     *
+     * See [PQgetCopyData](https://www.postgresql.org/docs/current/libpq-copy.html#LIBPQ-PQGETCOPYDATA).
+     */
+    pub fn get_copy_data(&self, async_: bool) -> CopyResult {
+        let mut buffer: *mut i8 = std::ptr::null_mut();
+
+        let len = unsafe { pq_sys::PQgetCopyData(self.into(), &mut buffer, async_ as i32) };
+
+        if len > 0 {
+            let data =
+                unsafe { std::slice::from_raw_parts(buffer as *const u8, len as usize) }.to_vec();
+            unsafe { pq_sys::PQfreemem(buffer as *mut std::ffi::c_void) };
+
+            CopyResult::Row(data)
+        } else if len == 0 {
+            CopyResult::WouldBlock
+        } else if len == -1 {
+            CopyResult::Done
+        } else {
+            CopyResult::Error(self.error_message().unwrap_or_default())
+        }
+    }
+
+    /**
+     * Returns a [`std::io::Write`] adapter for streaming a `COPY ... FROM STDIN` command.
+     */
+    pub fn copy_in(&self) -> CopyIn<'_> {
+        CopyIn { connection: self }
+    }
+
+    /**
+     * Returns a [`std::io::Read`] adapter for streaming a `COPY ... TO STDOUT` command.
+     */
+    pub fn copy_out(&self) -> CopyOut<'_> {
+        CopyOut {
+            connection: self,
+            buffer: std::collections::VecDeque::new(),
+            done: false,
+        }
+    }
+}
+
+/**
+ * Adapts a `COPY ... FROM STDIN` command to [`std::io::Write`].
+ *
+ * Created with [`Connection::copy_in`]. Dropping a `CopyIn` sends the
+ * end-of-data indication; callers that need to report an error should call
+ * [`finish`](CopyIn::finish) instead of relying on `Drop`.
+ */
+pub struct CopyIn<'a> {
+    connection: &'a crate::Connection,
+}
+
+impl CopyIn<'_> {
+    /**
+     * Sends the end-of-data indication, optionally forcing the COPY to
+     * fail with `error` as the error message, and consumes the adapter so
+     * `Drop` does not end the COPY a second time.
+     *
+     * See [`Connection::put_copy_end`].
+     */
+    pub fn finish(self, error: Option<&str>) -> crate::errors::Result {
+        let connection = self.connection;
+
+        std::mem::forget(self);
+
+        connection.put_copy_end(error)
+    }
+}
+
+impl std::io::Write for CopyIn<'_> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.connection
+            .put_copy_data(buf)
+            .map_err(|_| std::io::Error::other("PQputCopyData failed"))?;
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Drop for CopyIn<'_> {
+    fn drop(&mut self) {
+        let _ = self.connection.put_copy_end(None);
+    }
+}
+
+/**
+ * Adapts a `COPY ... TO STDOUT` command to [`std::io::Read`].
+ *
+ * Created with [`Connection::copy_out`].
+ */
+pub struct CopyOut<'a> {
+    connection: &'a crate::Connection,
+    buffer: std::collections::VecDeque<u8>,
+    done: bool,
+}
+
+impl std::io::Read for CopyOut<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        while self.buffer.is_empty() && !self.done {
+            match self.connection.get_copy_data(false) {
+                CopyResult::Row(data) => self.buffer.extend(data),
+                CopyResult::WouldBlock => continue,
+                CopyResult::Done => self.done = true,
+                CopyResult::Error(message) => return Err(std::io::Error::other(message)),
+            }
+        }
+
+        let len = std::cmp::min(buf.len(), self.buffer.len());
+
+        for (dst, src) in buf[..len].iter_mut().zip(self.buffer.drain(..len)) {
+            *dst = src;
+        }
+
+        Ok(len)
+    }
+}
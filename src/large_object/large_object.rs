@@ -0,0 +1,143 @@
+/**
+ * A handle to an open large object, obtained from
+ * [`large_object::open`](crate::large_object::open).
+ *
+ * Implements [`std::io::Read`], [`std::io::Write`], and [`std::io::Seek`]
+ * so large objects integrate with the standard IO traits.
+ */
+pub struct LargeObject<'a> {
+    pub(crate) connection: &'a crate::Connection,
+    pub(crate) fd: i32,
+}
+
+impl LargeObject<'_> {
+    /**
+     * Returns the current read/write location of the large object descriptor.
+     *
+     * See [lo_tell64](https://www.postgresql.org/docs/current/lo-interfaces.html#LO-TELL64).
+     */
+    pub fn tell(&self) -> crate::errors::Result<u64> {
+        crate::large_object::debug_assert_in_transaction(self.connection);
+
+        let offset = unsafe { pq_sys::lo_tell64(self.connection.into(), self.fd) };
+
+        if offset < 0 {
+            Err(crate::errors::Error::Unknow)
+        } else {
+            Ok(offset as u64)
+        }
+    }
+
+    /**
+     * Truncates the large object to `len` bytes.
+     *
+     * See [lo_truncate64](https://www.postgresql.org/docs/current/lo-interfaces.html#LO-TRUNCATE64).
+     */
+    pub fn truncate(&mut self, len: u64) -> crate::errors::Result {
+        crate::large_object::debug_assert_in_transaction(self.connection);
+
+        let success =
+            unsafe { pq_sys::lo_truncate64(self.connection.into(), self.fd, len as i64) };
+
+        if success < 0 {
+            Err(crate::errors::Error::Unknow)
+        } else {
+            Ok(())
+        }
+    }
+
+    /**
+     * Closes the large object descriptor.
+     *
+     * See [lo_close](https://www.postgresql.org/docs/current/lo-interfaces.html#LO-CLOSE).
+     */
+    pub fn close(self) -> crate::errors::Result {
+        crate::large_object::debug_assert_in_transaction(self.connection);
+
+        let connection = self.connection;
+        let fd = self.fd;
+
+        std::mem::forget(self);
+
+        let success = unsafe { pq_sys::lo_close(connection.into(), fd) };
+
+        if success < 0 {
+            Err(crate::errors::Error::Unknow)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl std::io::Read for LargeObject<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        crate::large_object::debug_assert_in_transaction(self.connection);
+
+        let n = unsafe {
+            pq_sys::lo_read(
+                self.connection.into(),
+                self.fd,
+                buf.as_mut_ptr() as *mut i8,
+                buf.len(),
+            )
+        };
+
+        if n < 0 {
+            Err(std::io::Error::other("lo_read failed"))
+        } else {
+            Ok(n as usize)
+        }
+    }
+}
+
+impl std::io::Write for LargeObject<'_> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        crate::large_object::debug_assert_in_transaction(self.connection);
+
+        let n = unsafe {
+            pq_sys::lo_write(
+                self.connection.into(),
+                self.fd,
+                buf.as_ptr() as *const i8,
+                buf.len(),
+            )
+        };
+
+        if n < 0 {
+            Err(std::io::Error::other("lo_write failed"))
+        } else {
+            Ok(n as usize)
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl std::io::Seek for LargeObject<'_> {
+    fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+        crate::large_object::debug_assert_in_transaction(self.connection);
+
+        let (offset, whence) = match pos {
+            std::io::SeekFrom::Start(n) => (n as i64, 0),
+            std::io::SeekFrom::Current(n) => (n, 1),
+            std::io::SeekFrom::End(n) => (n, 2),
+        };
+
+        let result =
+            unsafe { pq_sys::lo_lseek64(self.connection.into(), self.fd, offset, whence) };
+
+        if result < 0 {
+            Err(std::io::Error::other("lo_lseek64 failed"))
+        } else {
+            Ok(result as u64)
+        }
+    }
+}
+
+impl Drop for LargeObject<'_> {
+    fn drop(&mut self) {
+        unsafe { pq_sys::lo_close(self.connection.into(), self.fd) };
+    }
+}
@@ -0,0 +1,131 @@
+/*!
+ * [Large Objects](https://www.postgresql.org/docs/current/largeobjects.html)
+ *
+ * Every function in this module must be called within an SQL transaction
+ * block, since the server associates large object file descriptors with
+ * the current transaction and closes them at transaction end.
+ */
+
+mod large_object;
+
+pub use large_object::*;
+
+/**
+ * Flag for [`open`] requesting read access.
+ */
+pub const INV_READ: i32 = pq_sys::INV_READ as i32;
+
+/**
+ * Flag for [`open`] requesting write access.
+ */
+pub const INV_WRITE: i32 = pq_sys::INV_WRITE as i32;
+
+fn debug_assert_in_transaction(connection: &crate::Connection) {
+    debug_assert!(
+        !matches!(connection.transaction_status(), crate::TransactionStatus::Idle),
+        "large object calls must be made within a transaction block",
+    );
+}
+
+/**
+ * Creates a new, empty large object and returns its OID.
+ *
+ * See [lo_creat](https://www.postgresql.org/docs/current/lo-interfaces.html#LO-CREAT).
+ */
+pub fn create(connection: &crate::Connection, mode: i32) -> Option<crate::Oid> {
+    debug_assert_in_transaction(connection);
+
+    let oid = unsafe { pq_sys::lo_creat(connection.into(), mode) };
+
+    if oid == crate::oid::INVALID {
+        None
+    } else {
+        Some(oid)
+    }
+}
+
+/**
+ * Creates a new, empty large object with a given OID.
+ *
+ * See [lo_create](https://www.postgresql.org/docs/current/lo-interfaces.html#LO-CREATE).
+ */
+pub fn create_with_oid(connection: &crate::Connection, oid: crate::Oid) -> Option<crate::Oid> {
+    debug_assert_in_transaction(connection);
+
+    let oid = unsafe { pq_sys::lo_create(connection.into(), oid) };
+
+    if oid == crate::oid::INVALID {
+        None
+    } else {
+        Some(oid)
+    }
+}
+
+/**
+ * Opens an existing large object for reading and/or writing, as indicated
+ * by `mode` ([`INV_READ`] and/or [`INV_WRITE`]).
+ *
+ * See [lo_open](https://www.postgresql.org/docs/current/lo-interfaces.html#LO-OPEN).
+ */
+pub fn open(connection: &crate::Connection, oid: crate::Oid, mode: i32) -> Option<LargeObject<'_>> {
+    debug_assert_in_transaction(connection);
+
+    let fd = unsafe { pq_sys::lo_open(connection.into(), oid, mode) };
+
+    if fd < 0 {
+        None
+    } else {
+        Some(LargeObject { connection, fd })
+    }
+}
+
+/**
+ * Imports the contents of a file on the client into a new large object.
+ *
+ * See [lo_import](https://www.postgresql.org/docs/current/lo-interfaces.html#LO-IMPORT).
+ */
+pub fn import(connection: &crate::Connection, filename: &str) -> Option<crate::Oid> {
+    debug_assert_in_transaction(connection);
+
+    let oid = unsafe { pq_sys::lo_import(connection.into(), crate::cstr!(filename)) };
+
+    if oid == crate::oid::INVALID {
+        None
+    } else {
+        Some(oid)
+    }
+}
+
+/**
+ * Exports a large object into a file on the client.
+ *
+ * See [lo_export](https://www.postgresql.org/docs/current/lo-interfaces.html#LO-EXPORT).
+ */
+pub fn export(connection: &crate::Connection, oid: crate::Oid, filename: &str) -> crate::errors::Result {
+    debug_assert_in_transaction(connection);
+
+    let success = unsafe { pq_sys::lo_export(connection.into(), oid, crate::cstr!(filename)) };
+
+    if success < 0 {
+        Err(crate::errors::Error::Unknow)
+    } else {
+        Ok(())
+    }
+}
+
+/**
+ * Removes a large object from the database.
+ *
+ * See [lo_unlink](https://www.postgresql.org/docs/current/lo-interfaces.html#LO-UNLINK).
+ */
+pub fn unlink(connection: &crate::Connection, oid: crate::Oid) -> crate::errors::Result {
+    debug_assert_in_transaction(connection);
+
+    let success = unsafe { pq_sys::lo_unlink(connection.into(), oid) };
+
+    if success < 0 {
+        Err(crate::errors::Error::Unknow)
+    } else {
+        Ok(())
+    }
+}
@@ -0,0 +1,24 @@
+/**
+ * A notification received via `LISTEN`/`NOTIFY`, returned by
+ * [`Connection::notifies`](crate::Connection::notifies).
+ *
+ * See [PQnotifies](https://www.postgresql.org/docs/current/libpq-notify.html#LIBPQ-PQNOTIFIES).
+ */
+#[derive(Clone, Debug)]
+pub struct Notify {
+    /**
+     * The channel name given in the `NOTIFY` command.
+     */
+    pub channel: String,
+
+    /**
+     * The process ID of the notifying server backend.
+     */
+    pub backend_pid: i32,
+
+    /**
+     * The payload string given in the `NOTIFY` command, or empty if none
+     * was given.
+     */
+    pub payload: String,
+}
@@ -0,0 +1,43 @@
+/**
+ * Iterator over the columns of a `Result`, returned by [`Result::columns`].
+ *
+ * Yields `(name, type, format)` tuples built from
+ * [`field_name`](crate::Result::field_name),
+ * [`field_type`](crate::Result::field_type), and
+ * [`field_format`](crate::Result::field_format).
+ */
+pub struct Columns<'r> {
+    pub(crate) result: &'r crate::Result,
+    pub(crate) front: usize,
+    pub(crate) back: usize,
+}
+
+impl Iterator for Columns<'_> {
+    type Item = (Option<String>, Option<crate::Type>, crate::Format);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            None
+        } else {
+            let column = self.front;
+            self.front += 1;
+
+            Some((
+                self.result.field_name(column),
+                self.result.field_type(column),
+                self.result.field_format(column),
+            ))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Columns<'_> {
+    fn len(&self) -> usize {
+        self.back - self.front
+    }
+}
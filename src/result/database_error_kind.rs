@@ -0,0 +1,48 @@
+/**
+ * Classifies a `Result`'s error according to its SQLSTATE code.
+ *
+ * See the
+ * [PostgreSQL error codes appendix](https://www.postgresql.org/docs/current/errcodes-appendix.html)
+ * for the full list of SQLSTATE codes.
+ */
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DatabaseErrorKind {
+    UniqueViolation,
+    ForeignKeyViolation,
+    NotNullViolation,
+    CheckViolation,
+    SerializationFailure,
+    Deadlock,
+    Unknown,
+}
+
+impl DatabaseErrorKind {
+    pub(crate) fn from_sql_state(sql_state: &str) -> Self {
+        match sql_state {
+            "23505" => DatabaseErrorKind::UniqueViolation,
+            "23503" => DatabaseErrorKind::ForeignKeyViolation,
+            "23502" => DatabaseErrorKind::NotNullViolation,
+            "23514" => DatabaseErrorKind::CheckViolation,
+            "40001" => DatabaseErrorKind::SerializationFailure,
+            "40P01" => DatabaseErrorKind::Deadlock,
+            _ => DatabaseErrorKind::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_from_sql_state() {
+        assert_eq!(DatabaseErrorKind::from_sql_state("23505"), DatabaseErrorKind::UniqueViolation);
+        assert_eq!(DatabaseErrorKind::from_sql_state("23503"), DatabaseErrorKind::ForeignKeyViolation);
+        assert_eq!(DatabaseErrorKind::from_sql_state("23502"), DatabaseErrorKind::NotNullViolation);
+        assert_eq!(DatabaseErrorKind::from_sql_state("23514"), DatabaseErrorKind::CheckViolation);
+        assert_eq!(DatabaseErrorKind::from_sql_state("40001"), DatabaseErrorKind::SerializationFailure);
+        assert_eq!(DatabaseErrorKind::from_sql_state("40P01"), DatabaseErrorKind::Deadlock);
+        assert_eq!(DatabaseErrorKind::from_sql_state("42601"), DatabaseErrorKind::Unknown);
+        assert_eq!(DatabaseErrorKind::from_sql_state(""), DatabaseErrorKind::Unknown);
+    }
+}
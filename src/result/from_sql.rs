@@ -0,0 +1,192 @@
+/**
+ * Types that can be decoded from a column value returned by the server.
+ *
+ * An implementation receives the column's declared [`Type`](crate::Type)
+ * (`None` if the column's OID is not one of the types this crate
+ * recognizes), the [`Format`](crate::Format), and the raw bytes (`None`
+ * for `NULL`). `ty` is best-effort metadata only; decoding must not
+ * require it, since plenty of real-world columns (uuid, json/jsonb,
+ * numeric, enums, arrays, extension types, ...) have no recognized type.
+ */
+pub trait FromSql: Sized {
+    fn from_sql(
+        ty: Option<crate::Type>,
+        format: crate::Format,
+        raw: Option<&[u8]>,
+    ) -> std::result::Result<Self, ()>;
+}
+
+macro_rules! impl_from_sql_int {
+    ($ty:ty) => {
+        impl FromSql for $ty {
+            fn from_sql(
+                _ty: Option<crate::Type>,
+                format: crate::Format,
+                raw: Option<&[u8]>,
+            ) -> std::result::Result<Self, ()> {
+                let raw = raw.ok_or(())?;
+
+                match format {
+                    crate::Format::Binary => {
+                        let bytes = raw.try_into().map_err(|_| ())?;
+                        Ok(<$ty>::from_be_bytes(bytes))
+                    }
+                    crate::Format::Text => {
+                        std::str::from_utf8(raw)
+                            .map_err(|_| ())?
+                            .parse()
+                            .map_err(|_| ())
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl<T: FromSql> FromSql for Option<T> {
+    fn from_sql(
+        ty: Option<crate::Type>,
+        format: crate::Format,
+        raw: Option<&[u8]>,
+    ) -> std::result::Result<Self, ()> {
+        match raw {
+            Some(_) => T::from_sql(ty, format, raw).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+impl_from_sql_int!(i16);
+impl_from_sql_int!(i32);
+impl_from_sql_int!(i64);
+impl_from_sql_int!(f32);
+impl_from_sql_int!(f64);
+
+impl FromSql for bool {
+    fn from_sql(
+        _ty: Option<crate::Type>,
+        format: crate::Format,
+        raw: Option<&[u8]>,
+    ) -> std::result::Result<Self, ()> {
+        let raw = raw.ok_or(())?;
+
+        match format {
+            crate::Format::Binary => match raw {
+                [0] => Ok(false),
+                [1] => Ok(true),
+                _ => Err(()),
+            },
+            crate::Format::Text => match raw {
+                b"t" => Ok(true),
+                b"f" => Ok(false),
+                _ => Err(()),
+            },
+        }
+    }
+}
+
+impl FromSql for String {
+    fn from_sql(
+        _ty: Option<crate::Type>,
+        _format: crate::Format,
+        raw: Option<&[u8]>,
+    ) -> std::result::Result<Self, ()> {
+        let raw = raw.ok_or(())?;
+
+        String::from_utf8(raw.to_vec()).map_err(|_| ())
+    }
+}
+
+impl FromSql for Vec<u8> {
+    fn from_sql(
+        _ty: Option<crate::Type>,
+        format: crate::Format,
+        raw: Option<&[u8]>,
+    ) -> std::result::Result<Self, ()> {
+        let raw = raw.ok_or(())?;
+
+        match format {
+            crate::Format::Binary => Ok(raw.to_vec()),
+            crate::Format::Text => decode_bytea_hex(raw),
+        }
+    }
+}
+
+/**
+ * Decodes the `\x`-prefixed hex representation PostgreSQL uses for `bytea`
+ * values in text format.
+ */
+fn decode_bytea_hex(raw: &[u8]) -> std::result::Result<Vec<u8>, ()> {
+    let hex = raw.strip_prefix(b"\\x").ok_or(())?;
+
+    if hex.len() % 2 != 0 {
+        return Err(());
+    }
+
+    hex.chunks(2)
+        .map(|pair| {
+            let pair = std::str::from_utf8(pair).map_err(|_| ())?;
+            u8::from_str_radix(pair, 16).map_err(|_| ())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_decode_bytea_hex() {
+        assert_eq!(decode_bytea_hex(b"\\x"), Ok(vec![]));
+        assert_eq!(decode_bytea_hex(b"\\x0203e8"), Ok(vec![0x02, 0x03, 0xe8]));
+        assert_eq!(decode_bytea_hex(b"\\x020"), Err(()));
+        assert_eq!(decode_bytea_hex(b"0203e8"), Err(()));
+        assert_eq!(decode_bytea_hex(b"\\xzz"), Err(()));
+    }
+
+    #[test]
+    fn test_i32_from_sql_binary() {
+        assert_eq!(
+            i32::from_sql(None, crate::Format::Binary, Some(&1i32.to_be_bytes())),
+            Ok(1),
+        );
+        assert_eq!(
+            i32::from_sql(None, crate::Format::Binary, Some(&[0, 0, 1])),
+            Err(()),
+        );
+        assert_eq!(i32::from_sql(None, crate::Format::Binary, None), Err(()));
+    }
+
+    #[test]
+    fn test_i32_from_sql_text() {
+        assert_eq!(
+            i32::from_sql(None, crate::Format::Text, Some(b"42")),
+            Ok(42),
+        );
+        assert_eq!(
+            i32::from_sql(None, crate::Format::Text, Some(b"not a number")),
+            Err(()),
+        );
+    }
+
+    #[test]
+    fn test_bool_from_sql() {
+        assert_eq!(bool::from_sql(None, crate::Format::Binary, Some(&[1])), Ok(true));
+        assert_eq!(bool::from_sql(None, crate::Format::Binary, Some(&[0])), Ok(false));
+        assert_eq!(bool::from_sql(None, crate::Format::Binary, Some(&[2])), Err(()));
+        assert_eq!(bool::from_sql(None, crate::Format::Text, Some(b"t")), Ok(true));
+        assert_eq!(bool::from_sql(None, crate::Format::Text, Some(b"f")), Ok(false));
+    }
+
+    #[test]
+    fn test_option_from_sql() {
+        assert_eq!(
+            Option::<i32>::from_sql(None, crate::Format::Binary, None),
+            Ok(None),
+        );
+        assert_eq!(
+            Option::<i32>::from_sql(None, crate::Format::Binary, Some(&1i32.to_be_bytes())),
+            Ok(Some(1)),
+        );
+    }
+}
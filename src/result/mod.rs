@@ -1,8 +1,16 @@
 mod attribute;
+mod column;
+mod database_error_kind;
 mod error_field;
+mod from_sql;
+mod row;
 
 pub use attribute::*;
+pub use column::*;
+pub use database_error_kind::*;
 pub use error_field::*;
+pub use from_sql::*;
+pub use row::*;
 
 pub struct Result {
     result: *mut pq_sys::PGresult,
@@ -50,6 +58,54 @@ impl Result {
         })
     }
 
+    /**
+     * Classifies the error associated with the command according to its
+     * SQLSTATE code.
+     *
+     * Returns `None` if the command did not fail, i.e. if
+     * `error_field(ErrorField::SqlState)` is `None`.
+     */
+    pub fn error_kind(&self) -> Option<crate::result::DatabaseErrorKind> {
+        self.error_field(crate::result::ErrorField::SqlState)
+            .map(|sql_state| crate::result::DatabaseErrorKind::from_sql_state(&sql_state))
+    }
+
+    /**
+     * Returns the name of the constraint associated with the error, if any.
+     *
+     * See [`ErrorField::ConstraintName`](crate::result::ErrorField::ConstraintName).
+     */
+    pub fn error_constraint_name(&self) -> Option<String> {
+        self.error_field(crate::result::ErrorField::ConstraintName)
+    }
+
+    /**
+     * Returns the name of the table associated with the error, if any.
+     *
+     * See [`ErrorField::TableName`](crate::result::ErrorField::TableName).
+     */
+    pub fn error_table_name(&self) -> Option<String> {
+        self.error_field(crate::result::ErrorField::TableName)
+    }
+
+    /**
+     * Returns the name of the column associated with the error, if any.
+     *
+     * See [`ErrorField::ColumnName`](crate::result::ErrorField::ColumnName).
+     */
+    pub fn error_column_name(&self) -> Option<String> {
+        self.error_field(crate::result::ErrorField::ColumnName)
+    }
+
+    /**
+     * Returns the name of the schema associated with the error, if any.
+     *
+     * See [`ErrorField::SchemaName`](crate::result::ErrorField::SchemaName).
+     */
+    pub fn error_schema_name(&self) -> Option<String> {
+        self.error_field(crate::result::ErrorField::SchemaName)
+    }
+
     /**
      * Returns the number of rows (tuples) in the query result.
      *
@@ -68,6 +124,23 @@ impl Result {
         unsafe { pq_sys::PQnfields(self.into()) as usize }
     }
 
+    /**
+     * Returns an iterator over the rows of the query result.
+     *
+     * See [`Row`] for the operations available on a yielded row.
+     */
+    pub fn rows(&self) -> crate::result::Rows<'_> {
+        crate::result::Rows { result: self, front: 0, back: self.ntuples() }
+    }
+
+    /**
+     * Returns an iterator over the columns of the query result, yielding
+     * `(name, type, format)` tuples.
+     */
+    pub fn columns(&self) -> crate::result::Columns<'_> {
+        crate::result::Columns { result: self, front: 0, back: self.nfields() }
+    }
+
     /**
      * Returns the column name associated with the given column number.
      *
@@ -201,6 +274,41 @@ impl Result {
         }
     }
 
+    /**
+     * Decodes a single field value of one row of a `Result` into `T`.
+     *
+     * Dispatches on [`field_type`](Result::field_type) and
+     * [`field_format`](Result::field_format), so this works whether the
+     * column was fetched in text or binary format.
+     */
+    pub fn get<T: crate::result::FromSql>(&self, row: usize, column: usize) -> std::result::Result<T, ()> {
+        let ty = self.field_type(column);
+        let format = self.field_format(column);
+        let raw = self.value_bytes(row, column);
+
+        T::from_sql(ty, format, raw)
+    }
+
+    /**
+     * Returns a single field value of one row of a `Result` as raw bytes.
+     *
+     * Unlike [`value`](Result::value), this does not assume the value is
+     * text, so it does not stop at the first NUL byte. This is the
+     * counterpart to use when the column was fetched in binary format.
+     *
+     * See [PQgetvalue](https://www.postgresql.org/docs/current/libpq-exec.html#LIBPQ-PQGETVALUE).
+     */
+    pub fn value_bytes(&self, row: usize, column: usize) -> Option<&[u8]> {
+        if self.is_null(row, column) {
+            None
+        } else {
+            let raw = unsafe { pq_sys::PQgetvalue(self.into(), row as i32, column as i32) };
+            let len = self.length(row, column);
+
+            Some(unsafe { std::slice::from_raw_parts(raw as *const u8, len) })
+        }
+    }
+
     /**
      * Tests a field for a null value.
      *
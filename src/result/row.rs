@@ -0,0 +1,112 @@
+/**
+ * A handle to a single row of a `Result`, as yielded by [`Result::rows`].
+ *
+ * Borrowing the row out of the `Result` rather than copying its fields
+ * means libpq's O(1) random access to any tuple is preserved; no data is
+ * duplicated until a field is actually decoded with [`get`](Row::get).
+ */
+pub struct Row<'r> {
+    pub(crate) result: &'r crate::Result,
+    pub(crate) row: usize,
+}
+
+impl Row<'_> {
+    /**
+     * Decodes the value at `column` into `T`.
+     *
+     * See [`Result::get`].
+     */
+    pub fn get<T: crate::result::FromSql>(&self, column: usize) -> std::result::Result<T, ()> {
+        self.result.get(self.row, column)
+    }
+
+    /**
+     * Decodes the value of the column named `name` into `T`.
+     *
+     * See [`Result::field_number`].
+     */
+    pub fn get_by_name<T: crate::result::FromSql>(&self, name: &str) -> std::result::Result<T, ()> {
+        match self.result.field_number(name) {
+            Some(column) => self.get(column),
+            None => Err(()),
+        }
+    }
+
+    /**
+     * Tests `column` for a null value.
+     *
+     * See [`Result::is_null`].
+     */
+    pub fn is_null(&self, column: usize) -> bool {
+        self.result.is_null(self.row, column)
+    }
+
+    /**
+     * Returns the number of columns in the row.
+     *
+     * See [`Result::nfields`].
+     */
+    pub fn len(&self) -> usize {
+        self.result.nfields()
+    }
+
+    /**
+     * Returns `true` if the row has no columns.
+     */
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/**
+ * Iterator over the rows of a `Result`, returned by [`Result::rows`].
+ *
+ * Supports random access (`nth`) and double-ended iteration, since libpq
+ * gives O(1) access to any tuple.
+ */
+pub struct Rows<'r> {
+    pub(crate) result: &'r crate::Result,
+    pub(crate) front: usize,
+    pub(crate) back: usize,
+}
+
+impl<'r> Iterator for Rows<'r> {
+    type Item = Row<'r>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            None
+        } else {
+            let row = Row { result: self.result, row: self.front };
+            self.front += 1;
+            Some(row)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.front = self.front.saturating_add(n);
+        self.next()
+    }
+}
+
+impl DoubleEndedIterator for Rows<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            None
+        } else {
+            self.back -= 1;
+            Some(Row { result: self.result, row: self.back })
+        }
+    }
+}
+
+impl ExactSizeIterator for Rows<'_> {
+    fn len(&self) -> usize {
+        self.back - self.front
+    }
+}